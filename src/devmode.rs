@@ -0,0 +1,104 @@
+//! Shared `EnumDisplaySettingsExW`/`ChangeDisplaySettingsExW` plumbing.
+//!
+//! [`crate::DisplayDevice`] and [`crate::monitor::Monitor`] both address a
+//! device by its GDI device name (e.g. `\\.\DISPLAY1`) and wrap the same
+//! `DEVMODEW`-based mode enumeration/change calls; this is the one place
+//! that actually touches the FFI, so the two callers can't drift.
+
+use crate::display::{DisplayChangeError, DisplayMode};
+use std::{mem, ptr};
+use winapi::{
+    shared::minwindef::DWORD,
+    um::{
+        wingdi::DEVMODEW,
+        winuser::{
+            ChangeDisplaySettingsExW, EnumDisplaySettingsExW, CDS_UPDATEREGISTRY,
+            DM_BITSPERPEL, DM_DISPLAYFREQUENCY, DM_DISPLAYORIENTATION, DM_PELSHEIGHT,
+            DM_PELSWIDTH, DM_POSITION,
+        },
+    },
+};
+
+/// `EnumDisplaySettingsExW` for `device` (a null-terminated UTF-16 GDI device
+/// name) at `mode_num` (an index, or `ENUM_CURRENT_SETTINGS`).
+pub(crate) fn enum_mode(device: *const u16, mode_num: u32) -> Option<DisplayMode> {
+    let mut devmode = DEVMODEW {
+        dmSize: mem::size_of::<DEVMODEW>() as u16,
+        ..unsafe { mem::zeroed() }
+    };
+
+    let is_success = unsafe { EnumDisplaySettingsExW(device, mode_num, &mut devmode, 0) };
+    if !crate::unffi_bool(is_success) {
+        return None;
+    }
+
+    Some(DisplayMode::from_ffi(&devmode))
+}
+
+/// The `dmFields` every caller sets: resolution, refresh rate, and color
+/// depth.
+pub(crate) const MODE_FIELDS: DWORD = DM_PELSWIDTH | DM_PELSHEIGHT | DM_BITSPERPEL | DM_DISPLAYFREQUENCY;
+
+/// [`MODE_FIELDS`] plus `DM_DISPLAYORIENTATION`, for callers that also want
+/// to apply [`DisplayMode::orientation`].
+pub(crate) const MODE_FIELDS_WITH_ORIENTATION: DWORD = MODE_FIELDS | DM_DISPLAYORIENTATION;
+
+/// `ChangeDisplaySettingsExW` for `device`, setting resolution, refresh rate,
+/// and color depth from `mode`, plus orientation if `dm_fields` includes
+/// `DM_DISPLAYORIENTATION` (see [`MODE_FIELDS`]/[`MODE_FIELDS_WITH_ORIENTATION`]).
+pub(crate) fn change_mode(
+    device: *const u16,
+    mode: &DisplayMode,
+    dm_fields: DWORD,
+    flags: DWORD,
+) -> Result<(), DisplayChangeError> {
+    let mut devmode = DEVMODEW {
+        dmSize: mem::size_of::<DEVMODEW>() as u16,
+        dmFields: dm_fields,
+        dmPelsWidth: mode.width,
+        dmPelsHeight: mode.height,
+        dmBitsPerPel: mode.bits_per_pixel,
+        dmDisplayFrequency: mode.refresh_hz,
+        ..unsafe { mem::zeroed() }
+    };
+    if dm_fields & DM_DISPLAYORIENTATION != 0 {
+        unsafe {
+            devmode.u1.s2_mut().dmDisplayOrientation = mode.orientation.to_ffi();
+        }
+    }
+
+    apply(device, &mut devmode, flags)
+}
+
+/// `ChangeDisplaySettingsExW` moving `device` to `(x, y)` in virtual-screen
+/// coordinates.
+pub(crate) fn change_position(
+    device: *const u16,
+    x: i32,
+    y: i32,
+) -> Result<(), DisplayChangeError> {
+    let mut devmode = DEVMODEW {
+        dmSize: mem::size_of::<DEVMODEW>() as u16,
+        dmFields: DM_POSITION,
+        ..unsafe { mem::zeroed() }
+    };
+    unsafe {
+        let position = &mut devmode.u1.s2_mut().dmPosition;
+        position.x = x;
+        position.y = y;
+    }
+
+    apply(device, &mut devmode, CDS_UPDATEREGISTRY)
+}
+
+fn apply(
+    device: *const u16,
+    devmode: &mut DEVMODEW,
+    flags: DWORD,
+) -> Result<(), DisplayChangeError> {
+    let result = unsafe {
+        ChangeDisplaySettingsExW(device, devmode, ptr::null_mut(), flags, ptr::null_mut())
+    };
+
+    DisplayChangeError::from_code(result).map_or(Ok(()), Err)
+}
@@ -1,15 +1,21 @@
 use super::*;
+use crate::edid::{Edid, EdidError};
 use bitflags::bitflags;
 use derivative::Derivative;
+use lazy_static::lazy_static;
+use regex::Regex;
 use std::{
+    ffi::OsStr,
     fmt::{Debug, Display},
-    mem, ptr,
+    mem,
+    os::windows::ffi::OsStrExt,
+    ptr,
 };
 use thiserror::Error;
 use winapi::{
     ctypes::c_void,
     shared::{
-        minwindef::{HKEY, TRUE},
+        minwindef::{DWORD, HKEY, TRUE},
         windef::HDC__,
         winerror,
     },
@@ -20,7 +26,13 @@ use winapi::{
         wingdi::*,
         winnt::{KEY_READ, LPCWSTR},
         winreg::*,
-        winuser::{EnumDisplayDevicesW, EDD_GET_DEVICE_INTERFACE_NAME},
+        winuser::{
+            EnumDisplayDevicesW, CDS_UPDATEREGISTRY, DISP_CHANGE_BADDUALVIEW,
+            DISP_CHANGE_BADFLAGS, DISP_CHANGE_BADMODE, DISP_CHANGE_BADPARAM,
+            DISP_CHANGE_FAILED, DISP_CHANGE_NOTUPDATED, DISP_CHANGE_RESTART,
+            DISP_CHANGE_SUCCESSFUL, DMDO_180, DMDO_270, DMDO_90, DMDO_DEFAULT,
+            ENUM_CURRENT_SETTINGS, EDD_GET_DEVICE_INTERFACE_NAME,
+        },
     },
 };
 
@@ -71,6 +83,77 @@ impl DisplayDevice {
     /// println!("{:#?}", list);
     /// ```
     pub fn list() -> Vec<Self> {
+        Self::enumerate(ptr::null())
+    }
+
+    /// List the monitors attached to this adapter.
+    ///
+    /// [`Self::list`] only enumerates the top-level display adapters (e.g.
+    /// `\\.\DISPLAY1`); this re-invokes `EnumDisplayDevicesW` passing this
+    /// adapter's device name, which instead walks the monitors attached to
+    /// it, giving their own `DeviceString` (a human-readable monitor name
+    /// like "Generic PnP Monitor"), `DeviceID`, and `StateFlags`.
+    ///
+    /// ```
+    /// # use monitor_control_win::DisplayDevice;
+    /// let adapter = DisplayDevice::primary()?;
+    /// let monitors = adapter.monitors();
+    /// println!("{:#?}", monitors);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn monitors(&self) -> Vec<Self> {
+        Self::enumerate(&self.ffi_device[0])
+    }
+
+    /// Like [`Self::monitors`], but skips monitors that aren't active
+    /// (`!DISPLAY_DEVICE_ACTIVE`) or are a mirroring pseudo-device
+    /// (`DISPLAY_DEVICE_MIRRORING_DRIVER`), which don't correspond to a
+    /// physical panel.
+    ///
+    /// ```
+    /// # use monitor_control_win::DisplayDevice;
+    /// let adapter = DisplayDevice::primary()?;
+    /// let monitors = adapter.active_monitors();
+    /// println!("{:#?}", monitors);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn active_monitors(&self) -> Vec<Self> {
+        self.monitors()
+            .into_iter()
+            .filter(|m| m.state.contains(State::ACTIVE) && !m.state.contains(State::MIRRORING_DRIVER))
+            .collect()
+    }
+
+    /// A human-readable name for this device, e.g. "Generic PnP Monitor" for
+    /// a monitor-level entry. This is the same as [`Self::string`], named to
+    /// make its purpose clearer when correlating devices with other APIs.
+    pub fn readable_name(&self) -> &str {
+        &self.string
+    }
+
+    /// Whether this is the primary device (`DISPLAY_DEVICE_PRIMARY_DEVICE`).
+    pub fn is_primary(&self) -> bool {
+        self.state.contains(State::PRIMARY_DEVICE)
+    }
+
+    /// Extract the hardware driver id (e.g. `GSM598E`) from this device's
+    /// `DeviceID`, for monitor-level entries shaped like
+    /// `MONITOR\<driver id>\{<class guid>}\<instance>`.
+    ///
+    /// This is the same id used as the registry key name under
+    /// `SYSTEM\CurrentControlSet\Enum\DISPLAY`, so it can be used to
+    /// correlate a monitor-level `DisplayDevice` (which has a friendly name
+    /// and position) with the EDID-bearing registry `Monitor`.
+    pub fn driver_id(&self) -> Option<&str> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^MONITOR\\(?P<driver>[A-Za-z0-9]+)\\").unwrap();
+        }
+
+        RE.captures(&self.id)
+            .map(|caps| caps.name("driver").unwrap().as_str())
+    }
+
+    fn enumerate(adapter: LPCWSTR) -> Vec<Self> {
         let mut display = DISPLAY_DEVICEW {
             cb: mem::size_of::<DISPLAY_DEVICEW>() as u32,
             ..Default::default()
@@ -78,7 +161,7 @@ impl DisplayDevice {
 
         let mut list = Vec::new();
         let mut n = 0;
-        while unsafe { EnumDisplayDevicesW(ptr::null(), n, &mut display, 0) } != 0 {
+        while unsafe { EnumDisplayDevicesW(adapter, n, &mut display, 0) } != 0 {
             let name = wchars_to_string(&display.DeviceName);
             let string = wchars_to_string(&display.DeviceString);
             let state = State::from_bits(display.StateFlags).expect("Valid device state bitflags");
@@ -165,6 +248,118 @@ impl DisplayDevice {
         Ok(space.into())
     }
 
+    /// Get the mode this device is currently using.
+    ///
+    /// ```
+    /// # use monitor_control_win::DisplayDevice;
+    /// let device = DisplayDevice::primary()?;
+    /// let mode = device.current_mode()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn current_mode(&self) -> Result<DisplayMode, DisplayDeviceError> {
+        self.mode_at(ENUM_CURRENT_SETTINGS)
+            .ok_or(DisplayDeviceError::GetCurrentMode)
+    }
+
+    /// List every mode supported by this device.
+    ///
+    /// ```
+    /// # use monitor_control_win::DisplayDevice;
+    /// let device = DisplayDevice::primary()?;
+    /// let modes = device.modes();
+    /// println!("{:#?}", modes);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn modes(&self) -> Vec<DisplayMode> {
+        let mut modes = vec![];
+        let mut n = 0;
+        while let Some(mode) = self.mode_at(n) {
+            modes.push(mode);
+            n += 1;
+        }
+        modes
+    }
+
+    fn mode_at(&self, mode_num: u32) -> Option<DisplayMode> {
+        devmode::enum_mode(&self.ffi_device[0], mode_num)
+    }
+
+    /// Change this device's mode.
+    ///
+    /// This only changes the resolution, refresh rate, and color depth of
+    /// this device; [`DisplayMode::position`] and [`DisplayMode::orientation`]
+    /// are not applied.
+    ///
+    /// ```no_run
+    /// # use monitor_control_win::DisplayDevice;
+    /// let device = DisplayDevice::primary()?;
+    /// let mode = device.current_mode()?;
+    /// device.set_mode(&mode)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_mode(&self, mode: &DisplayMode) -> Result<(), DisplayDeviceError> {
+        devmode::change_mode(
+            &self.ffi_device[0],
+            mode,
+            devmode::MODE_FIELDS,
+            CDS_UPDATEREGISTRY,
+        )
+        .map_err(DisplayDeviceError::SetMode)
+    }
+
+    /// Get and parse this monitor's Extended Device Identification Data.
+    ///
+    /// ```
+    /// # use monitor_control_win::DisplayDevice;
+    /// let device = DisplayDevice::primary()?;
+    /// let edid = device.edid()?;
+    /// println!("{:#?}", edid);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn edid(&self) -> Result<Edid, DisplayDeviceError> {
+        let key = self.reg_key()?;
+
+        let value_name: Vec<u16> = OsStr::new("EDID").encode_wide().chain(Some(0)).collect();
+
+        let mut len = 0u32;
+        let status = unsafe {
+            RegQueryValueExW(
+                key,
+                value_name.as_ptr(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                &mut len,
+            )
+        };
+        if status as u32 != winerror::ERROR_SUCCESS {
+            unsafe {
+                RegCloseKey(key);
+            }
+            return Err(DisplayDeviceError::GetEdid(status.into()));
+        }
+
+        let mut data = vec![0u8; len as usize];
+        let status = unsafe {
+            RegQueryValueExW(
+                key,
+                value_name.as_ptr(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                data.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        unsafe {
+            RegCloseKey(key);
+        }
+        if status as u32 != winerror::ERROR_SUCCESS {
+            return Err(DisplayDeviceError::GetEdid(status.into()));
+        }
+
+        Edid::parse(&data).map_err(DisplayDeviceError::ParseEdid)
+    }
+
     fn reg_values(&self) -> Result<Vec<String>, DisplayDeviceError> {
         let parent = self.reg_key()?;
 
@@ -484,6 +679,114 @@ fn fxp_8dot8_to_f32(fxp: u32) -> f32 {
     (fxp as f32) / EIGHT_ZEROS
 }
 
+/// A display mode: resolution, refresh rate, color depth, and position.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DisplayMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_hz: u32,
+    pub bits_per_pixel: u32,
+    /// Position in virtual-screen coordinates.
+    pub position: (i32, i32),
+    pub orientation: Orientation,
+}
+
+impl DisplayMode {
+    pub(crate) fn from_ffi(devmode: &DEVMODEW) -> Self {
+        // `dmPosition`/`dmDisplayOrientation` live in the anonymous union at
+        // `u1`; `s2` is the display-device view of it (as opposed to `s1`,
+        // the printer-device view).
+        let u1 = unsafe { devmode.u1.s2() };
+
+        Self {
+            width: devmode.dmPelsWidth,
+            height: devmode.dmPelsHeight,
+            refresh_hz: devmode.dmDisplayFrequency,
+            bits_per_pixel: devmode.dmBitsPerPel,
+            position: (u1.dmPosition.x, u1.dmPosition.y),
+            orientation: Orientation::from_ffi(u1.dmDisplayOrientation),
+        }
+    }
+}
+
+/// The rotation of a display, as reported by `dmDisplayOrientation`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Orientation {
+    Default,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+impl Orientation {
+    fn from_ffi(ffi: DWORD) -> Self {
+        match ffi {
+            DMDO_90 => Self::Rotate90,
+            DMDO_180 => Self::Rotate180,
+            DMDO_270 => Self::Rotate270,
+            _ => Self::Default,
+        }
+    }
+
+    pub(crate) fn to_ffi(self) -> DWORD {
+        match self {
+            Self::Default => DMDO_DEFAULT,
+            Self::Rotate90 => DMDO_90,
+            Self::Rotate180 => DMDO_180,
+            Self::Rotate270 => DMDO_270,
+        }
+    }
+}
+
+/// Why `ChangeDisplaySettingsExW` rejected a mode change.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DisplayChangeError {
+    /// The computer must be restarted for the change to take effect.
+    Restart,
+    /// The settings change was unsuccessful because the system is DualView
+    /// capable.
+    BadDualView,
+    /// An invalid set of flags was passed in.
+    BadFlags,
+    /// The settings change was unsuccessful because the mode is not
+    /// supported.
+    BadMode,
+    /// An invalid parameter was passed in, e.g. an invalid flag was set.
+    BadParam,
+    /// The display driver failed the specified graphics mode.
+    Failed,
+    /// The settings change was successful, but not saved to the registry.
+    NotUpdated,
+    /// An unrecognized `DISP_CHANGE_*` code.
+    Unknown(i32),
+}
+
+impl DisplayChangeError {
+    /// Convert a `ChangeDisplaySettingsExW` return code, or `None` if it was
+    /// `DISP_CHANGE_SUCCESSFUL`.
+    pub(crate) fn from_code(code: i32) -> Option<Self> {
+        match code {
+            DISP_CHANGE_SUCCESSFUL => None,
+            DISP_CHANGE_RESTART => Some(Self::Restart),
+            DISP_CHANGE_BADDUALVIEW => Some(Self::BadDualView),
+            DISP_CHANGE_BADFLAGS => Some(Self::BadFlags),
+            DISP_CHANGE_BADMODE => Some(Self::BadMode),
+            DISP_CHANGE_BADPARAM => Some(Self::BadParam),
+            DISP_CHANGE_FAILED => Some(Self::Failed),
+            DISP_CHANGE_NOTUPDATED => Some(Self::NotUpdated),
+            other => Some(Self::Unknown(other)),
+        }
+    }
+}
+
+impl Display for DisplayChangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for DisplayChangeError {}
+
 #[derive(Error, Debug, Eq, PartialEq, Clone)]
 pub enum DisplayDeviceError {
     #[error("No primary device exists. Are you running interactively?")]
@@ -500,6 +803,14 @@ pub enum DisplayDeviceError {
     GetInfoSetData(#[source] WinError),
     #[error("Failed to get interface name: does not exist")]
     GetNonexistentInterfaceName,
+    #[error("Failed to get the device's current display mode")]
+    GetCurrentMode,
+    #[error("Failed to change the device's display mode")]
+    SetMode(#[source] DisplayChangeError),
+    #[error("Failed to get EDID from the device registry key")]
+    GetEdid(#[source] WinError),
+    #[error("Failed to parse EDID")]
+    ParseEdid(#[source] EdidError),
 }
 
 #[cfg(test)]
@@ -546,14 +857,39 @@ mod tests {
         }
     }
 
-    // #[test]
-    // fn at_least_one_device_has_edid() {
-    //     let edids = DisplayDevice::list()
-    //         .iter()
-    //         .map(DisplayDevice::edid)
-    //         .collect::<Vec<_>>();
-    //     panic!("{:#?}", edids);
-    // }
+    fn device_with_id(id: &str) -> DisplayDevice {
+        DisplayDevice {
+            name: String::new(),
+            string: String::new(),
+            state: State::empty(),
+            id: id.to_string(),
+            key: String::new(),
+            ffi_device: [0; 32],
+            ffi_key: [0; 128],
+            ffi_id: [0; 128],
+        }
+    }
+
+    #[test]
+    fn parses_monitor_device_id() {
+        let device = device_with_id(r"MONITOR\GSM598E\{4d36e96e-e325-11ce-bfc1-08002be10318}\0001");
+        assert_eq!(device.driver_id(), Some("GSM598E"));
+    }
+
+    #[test]
+    fn rejects_non_monitor_device_id() {
+        let device = device_with_id(r"PCI\VEN_10DE&DEV_1234&SUBSYS_00000000\3&11583659&0&10");
+        assert_eq!(device.driver_id(), None);
+    }
+
+    #[test]
+    fn at_least_one_device_has_edid() {
+        let edids = DisplayDevice::list()
+            .iter()
+            .map(DisplayDevice::edid)
+            .collect::<Vec<_>>();
+        panic!("{:#?}", edids);
+    }
 
     #[test]
     fn can_get_info() {
@@ -0,0 +1,280 @@
+//! Friendly monitor names and connector types via the DisplayConfig API
+//! (`QueryDisplayConfig`/`DisplayConfigGetDeviceInfo`).
+//!
+//! Unlike [`crate::Monitor`] and [`crate::DisplayDevice`], which only see
+//! opaque adapter-level names, this gives the OS "friendly name" (e.g.
+//! "DELL U2720Q") and how the panel is physically connected.
+
+use crate::{wchars_to_string, DisplayDevice, State, WinError};
+use std::{mem, ptr};
+use thiserror::Error;
+use winapi::{
+    shared::{minwindef::UINT32, ntdef::LUID, winerror::ERROR_SUCCESS},
+    um::wingdi::{
+        DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_PATH_INFO,
+        DISPLAYCONFIG_SOURCE_DEVICE_NAME, DISPLAYCONFIG_TARGET_DEVICE_NAME,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_DVI, DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_HD15,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_HDMI,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_INTERNAL,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_UDI,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_VIRTUAL,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_WIRELESS_DISPLAY,
+    },
+    um::winuser::{
+        DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+        QDC_ONLY_ACTIVE_PATHS,
+    },
+};
+
+/// One active DisplayConfig target (roughly: one connected monitor).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayConfigTarget {
+    pub adapter_id: Luid,
+    pub target_id: UINT32,
+    /// The OS "friendly name" for the monitor, e.g. "DELL U2720Q". Empty if
+    /// the monitor doesn't report one (e.g. some generic/virtual displays).
+    pub friendly_name: String,
+    pub connection: ConnectionKind,
+    /// The GDI device name of the adapter this target is on, e.g.
+    /// `\\.\DISPLAY1`. Matches [`DisplayDevice::name`], so it can be used to
+    /// join a friendly name/connector type back onto a GDI device.
+    pub adapter_gdi_device_name: String,
+}
+
+impl DisplayConfigTarget {
+    /// Find the [`DisplayDevice`] this target is attached to, by matching
+    /// [`Self::adapter_gdi_device_name`] against [`DisplayDevice::name`].
+    pub fn resolve<'a>(&self, devices: &'a [DisplayDevice]) -> Option<&'a DisplayDevice> {
+        devices
+            .iter()
+            .find(|d| d.name == self.adapter_gdi_device_name)
+    }
+}
+
+/// The LUID (locally unique identifier) of a display adapter, as reported by
+/// the DisplayConfig API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Luid {
+    pub low_part: u32,
+    pub high_part: i32,
+}
+
+impl From<LUID> for Luid {
+    fn from(ffi: LUID) -> Self {
+        Self {
+            low_part: ffi.LowPart,
+            high_part: ffi.HighPart,
+        }
+    }
+}
+
+impl From<Luid> for LUID {
+    fn from(luid: Luid) -> Self {
+        Self {
+            LowPart: luid.low_part,
+            HighPart: luid.high_part,
+        }
+    }
+}
+
+/// How a monitor is physically connected, from `DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionKind {
+    Hd15,
+    Dvi,
+    Hdmi,
+    DisplayPortExternal,
+    DisplayPortEmbedded,
+    Udi,
+    Wireless,
+    Virtual,
+    /// An internally connected panel, e.g. a laptop's built-in display.
+    Internal,
+    Other(i32),
+}
+
+impl From<i32> for ConnectionKind {
+    fn from(ffi: i32) -> Self {
+        match ffi {
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_HD15 => Self::Hd15,
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_DVI => Self::Dvi,
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_HDMI => Self::Hdmi,
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL => {
+                Self::DisplayPortExternal
+            }
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED => {
+                Self::DisplayPortEmbedded
+            }
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_UDI => Self::Udi,
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_WIRELESS_DISPLAY => Self::Wireless,
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_VIRTUAL => Self::Virtual,
+            DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY_INTERNAL => Self::Internal,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Get the friendly name, connection type, and owning adapter of every
+/// currently active DisplayConfig target.
+///
+/// ```no_run
+/// # use monitor_control_win::display_config::targets;
+/// let targets = targets()?;
+/// println!("{:#?}", targets);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn targets() -> Result<Vec<DisplayConfigTarget>, DisplayConfigError> {
+    let paths = query_paths()?;
+
+    let mut out = Vec::with_capacity(paths.len());
+    for path in &paths {
+        let target = &path.targetInfo;
+        let (friendly_name, connection) = target_name(target.adapterId, target.id)?;
+
+        let source = &path.sourceInfo;
+        let adapter_gdi_device_name = source_name(source.adapterId, source.id)?;
+
+        out.push(DisplayConfigTarget {
+            adapter_id: target.adapterId.into(),
+            target_id: target.id,
+            friendly_name,
+            connection,
+            adapter_gdi_device_name,
+        });
+    }
+
+    Ok(out)
+}
+
+fn query_paths() -> Result<Vec<DISPLAYCONFIG_PATH_INFO>, DisplayConfigError> {
+    let mut num_paths = 0u32;
+    let mut num_modes = 0u32;
+
+    let status = unsafe {
+        GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut num_paths, &mut num_modes)
+    };
+    if status as u32 != ERROR_SUCCESS {
+        return Err(DisplayConfigError::GetBufferSizes(status.into()));
+    }
+
+    let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); num_paths as usize];
+    let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); num_modes as usize];
+
+    let status = unsafe {
+        QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut num_paths,
+            paths.as_mut_ptr(),
+            &mut num_modes,
+            modes.as_mut_ptr(),
+            ptr::null_mut(),
+        )
+    };
+    if status as u32 != ERROR_SUCCESS {
+        return Err(DisplayConfigError::Query(status.into()));
+    }
+
+    paths.truncate(num_paths as usize);
+
+    Ok(paths)
+}
+
+fn target_name(
+    adapter_id: LUID,
+    target_id: UINT32,
+) -> Result<(String, ConnectionKind), DisplayConfigError> {
+    let mut request = DISPLAYCONFIG_TARGET_DEVICE_NAME::default();
+    request.header = DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        size: mem::size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME>() as u32,
+        adapterId: adapter_id,
+        id: target_id,
+    };
+
+    let status = unsafe { DisplayConfigGetDeviceInfo(&mut request.header) };
+    if status as u32 != ERROR_SUCCESS {
+        return Err(DisplayConfigError::GetDeviceInfo(status.into()));
+    }
+
+    let friendly_name = wchars_to_string(&request.monitorFriendlyDeviceName);
+    let connection = ConnectionKind::from(request.outputTechnology);
+
+    Ok((friendly_name, connection))
+}
+
+fn source_name(adapter_id: LUID, source_id: UINT32) -> Result<String, DisplayConfigError> {
+    let mut request = DISPLAYCONFIG_SOURCE_DEVICE_NAME::default();
+    request.header = DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+        size: mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32,
+        adapterId: adapter_id,
+        id: source_id,
+    };
+
+    let status = unsafe { DisplayConfigGetDeviceInfo(&mut request.header) };
+    if status as u32 != ERROR_SUCCESS {
+        return Err(DisplayConfigError::GetDeviceInfo(status.into()));
+    }
+
+    Ok(wchars_to_string(&request.viewGdiDeviceName))
+}
+
+#[derive(Debug, Error, Eq, PartialEq, Clone, Copy)]
+pub enum DisplayConfigError {
+    #[error("Failed to get DisplayConfig buffer sizes")]
+    GetBufferSizes(#[source] WinError),
+    #[error("Failed to query DisplayConfig paths/modes")]
+    Query(#[source] WinError),
+    #[error("Failed to get DisplayConfig device info")]
+    GetDeviceInfo(#[source] WinError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_get_targets() {
+        let targets = targets().unwrap();
+        panic!("{:#?}", targets);
+    }
+
+    fn sample_target() -> DisplayConfigTarget {
+        DisplayConfigTarget {
+            adapter_id: Luid {
+                low_part: 1,
+                high_part: 0,
+            },
+            target_id: 0,
+            friendly_name: "DELL U2720Q".to_string(),
+            connection: ConnectionKind::DisplayPortExternal,
+            adapter_gdi_device_name: r"\\.\DISPLAY1".to_string(),
+        }
+    }
+
+    #[test]
+    fn resolves_matching_device() {
+        let target = sample_target();
+        let device = DisplayDevice {
+            name: target.adapter_gdi_device_name.clone(),
+            string: String::new(),
+            state: State::empty(),
+            id: String::new(),
+            key: String::new(),
+            ffi_device: [0; 32],
+            ffi_key: [0; 128],
+            ffi_id: [0; 128],
+        };
+
+        assert_eq!(target.resolve(&[device.clone()]), Some(&device));
+    }
+
+    #[test]
+    fn resolve_returns_none_without_a_match() {
+        let target = sample_target();
+        assert_eq!(target.resolve(&[]), None);
+    }
+}
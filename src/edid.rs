@@ -0,0 +1,226 @@
+use thiserror::Error;
+
+/// Parsed contents of a monitor's EDID (Extended Display Identification
+/// Data) base block.
+///
+/// Only the fixed 128-byte base block is parsed; extension blocks (e.g. for
+/// additional detailed timings) are not read.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Edid {
+    pub manufacturer: String,
+    pub product_code: u16,
+    pub serial: u32,
+    pub manufacture_week: u8,
+    pub manufacture_year: u16,
+    /// Physical size of the screen in centimeters, or `None` if the monitor
+    /// doesn't report a size (e.g. a projector).
+    pub physical_size_cm: Option<(u8, u8)>,
+    pub display_name: Option<String>,
+    /// The serial number descriptor (tag `0xFF`), if present. This is a
+    /// separate ASCII field from [`Self::serial`], which is a fixed-width
+    /// integer serial set by some manufacturers instead.
+    pub serial_string: Option<String>,
+    /// The display's preferred timing mode, if a detailed timing descriptor
+    /// is present. By spec this is always the descriptor at offset 54 when
+    /// present, so it's also the first one found.
+    pub preferred_timing: Option<DetailedTiming>,
+}
+
+const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+const DESCRIPTOR_OFFSETS: [usize; 4] = [54, 72, 90, 108];
+const DESCRIPTOR_TAG_MONITOR_NAME: u8 = 0xFC;
+const DESCRIPTOR_TAG_SERIAL_STRING: u8 = 0xFF;
+
+impl Edid {
+    /// Parse the 128-byte EDID base block.
+    pub fn parse(bytes: &[u8]) -> Result<Self, EdidError> {
+        if bytes.len() < 128 {
+            return Err(EdidError::TooShort(bytes.len()));
+        }
+        let bytes = &bytes[..128];
+
+        if bytes[..8] != HEADER {
+            return Err(EdidError::BadHeader);
+        }
+
+        let checksum = bytes.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        if checksum != 0 {
+            return Err(EdidError::BadChecksum);
+        }
+
+        let manufacturer = parse_manufacturer(bytes[8], bytes[9]);
+        let product_code = u16::from_le_bytes([bytes[10], bytes[11]]);
+        let serial = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
+        let manufacture_week = bytes[16];
+        let manufacture_year = bytes[17] as u16 + 1990;
+
+        let physical_size_cm = match (bytes[21], bytes[22]) {
+            (0, 0) => None,
+            size => Some(size),
+        };
+
+        let mut display_name = None;
+        let mut serial_string = None;
+        let mut preferred_timing = None;
+        for &offset in &DESCRIPTOR_OFFSETS {
+            let descriptor = &bytes[offset..offset + 18];
+            if descriptor[0] == 0 && descriptor[1] == 0 {
+                // A non-timing descriptor: zeroed pixel clock, then a tag at
+                // byte 3.
+                match descriptor[3] {
+                    DESCRIPTOR_TAG_MONITOR_NAME => {
+                        display_name = Some(parse_descriptor_text(&descriptor[5..18]));
+                    }
+                    DESCRIPTOR_TAG_SERIAL_STRING => {
+                        serial_string = Some(parse_descriptor_text(&descriptor[5..18]));
+                    }
+                    _ => {}
+                }
+            } else if preferred_timing.is_none() {
+                // A detailed timing descriptor: non-zero pixel clock.
+                preferred_timing = Some(DetailedTiming::from_descriptor(descriptor));
+            }
+        }
+
+        Ok(Self {
+            manufacturer,
+            product_code,
+            serial,
+            manufacture_week,
+            manufacture_year,
+            physical_size_cm,
+            display_name,
+            serial_string,
+            preferred_timing,
+        })
+    }
+}
+
+/// A detailed timing descriptor. This is the only EDID descriptor type that
+/// actually describes a video mode, as opposed to plain text (monitor name,
+/// serial string).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DetailedTiming {
+    pub pixel_clock_khz: u32,
+    pub h_active_px: u16,
+    pub v_active_px: u16,
+}
+
+impl DetailedTiming {
+    fn from_descriptor(descriptor: &[u8]) -> Self {
+        let pixel_clock_khz = u16::from_le_bytes([descriptor[0], descriptor[1]]) as u32 * 10;
+        // Active pixel counts are split: low 8 bits in their own byte, high
+        // 4 bits in the top nibble of a byte shared with the blanking count.
+        let h_active_px = descriptor[2] as u16 | (((descriptor[4] >> 4) as u16) << 8);
+        let v_active_px = descriptor[5] as u16 | (((descriptor[7] >> 4) as u16) << 8);
+
+        Self {
+            pixel_clock_khz,
+            h_active_px,
+            v_active_px,
+        }
+    }
+}
+
+fn parse_manufacturer(b8: u8, b9: u8) -> String {
+    let packed = u16::from_be_bytes([b8, b9]);
+    let letter = |shift: u16| {
+        let value = ((packed >> shift) & 0b1_1111) as u8;
+        (value + b'A' - 1) as char
+    };
+    [letter(10), letter(5), letter(0)].iter().collect()
+}
+
+fn parse_descriptor_text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0x0A).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim_end().to_string()
+}
+
+#[derive(Debug, Error, Eq, PartialEq, Clone, Copy)]
+pub enum EdidError {
+    #[error("EDID data is too short: expected at least 128 bytes, got {0}")]
+    TooShort(usize),
+    #[error("EDID data does not start with the expected 8-byte header")]
+    BadHeader,
+    #[error("EDID checksum does not sum to 0 mod 256")]
+    BadChecksum,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_bytes() -> Vec<u8> {
+        let mut bytes = vec![0u8; 128];
+        bytes[..8].copy_from_slice(&HEADER);
+        // Manufacturer "DEL", packed as 3 5-bit letters.
+        bytes[8] = 0x10;
+        bytes[9] = 0xAC;
+        bytes[10..12].copy_from_slice(&1234u16.to_le_bytes());
+        bytes[12..16].copy_from_slice(&1u32.to_le_bytes());
+        bytes[16] = 1; // week
+        bytes[17] = 30; // year = 1990 + 30
+        bytes[21] = 60;
+        bytes[22] = 34;
+
+        let name_descriptor = &mut bytes[54..54 + 18];
+        name_descriptor[3] = DESCRIPTOR_TAG_MONITOR_NAME;
+        name_descriptor[5..5 + 5].copy_from_slice(b"U2720");
+        name_descriptor[10] = 0x0A; // text terminator
+
+        let serial_descriptor = &mut bytes[72..72 + 18];
+        serial_descriptor[3] = DESCRIPTOR_TAG_SERIAL_STRING;
+        serial_descriptor[5..5 + 6].copy_from_slice(b"ABC123");
+        serial_descriptor[11] = 0x0A; // text terminator
+
+        // A detailed timing descriptor: 148,500 kHz pixel clock, 3840x2160
+        // active area.
+        let timing_descriptor = &mut bytes[90..90 + 18];
+        timing_descriptor[0..2].copy_from_slice(&14850u16.to_le_bytes());
+        timing_descriptor[2] = 0x00; // h active low byte
+        timing_descriptor[4] = 0xF0; // h active high nibble = 0xF00 = 3840
+        timing_descriptor[5] = 0x70; // v active low byte
+        timing_descriptor[7] = 0x80; // v active high nibble = 0x870 = 2160
+
+        let checksum = bytes[..127].iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        bytes[127] = checksum.wrapping_neg();
+
+        bytes
+    }
+
+    #[test]
+    fn parses_sample_edid() {
+        let edid = Edid::parse(&sample_bytes()).unwrap();
+
+        assert_eq!(edid.manufacturer, "DEL");
+        assert_eq!(edid.product_code, 1234);
+        assert_eq!(edid.serial, 1);
+        assert_eq!(edid.manufacture_week, 1);
+        assert_eq!(edid.manufacture_year, 2020);
+        assert_eq!(edid.physical_size_cm, Some((60, 34)));
+        assert_eq!(edid.display_name.as_deref(), Some("U2720"));
+        assert_eq!(edid.serial_string.as_deref(), Some("ABC123"));
+        assert_eq!(
+            edid.preferred_timing,
+            Some(DetailedTiming {
+                pixel_clock_khz: 148_500,
+                h_active_px: 3840,
+                v_active_px: 2160,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let mut bytes = sample_bytes();
+        bytes[127] = bytes[127].wrapping_add(1);
+        assert_eq!(Edid::parse(&bytes), Err(EdidError::BadChecksum));
+    }
+
+    #[test]
+    fn rejects_bad_header() {
+        let mut bytes = sample_bytes();
+        bytes[0] = 0xFF;
+        assert_eq!(Edid::parse(&bytes), Err(EdidError::BadHeader));
+    }
+}
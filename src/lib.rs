@@ -1,5 +1,11 @@
 #![warn(clippy::cargo)]
 
+pub(crate) mod devmode;
+pub mod display_config;
+pub mod edid;
+pub mod watcher;
+
+use edid::{Edid, EdidError};
 use lazy_static::lazy_static;
 use regex::Regex;
 use registry::{Hive, RegKey, Security};
@@ -190,6 +196,24 @@ impl Monitor {
         Ok(bytes)
     }
 
+    /// Get and parse the Extended Device Identification Data of a monitor,
+    /// without needing an external EDID-parsing crate.
+    ///
+    /// ```
+    /// # use monitor_control_win::Monitor;
+    /// let monitor = Monitor::all()?.remove(0);
+    /// let edid = monitor.parse_edid()?;
+    /// println!("{:#?}", edid);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_edid(&self) -> Result<Edid, MonitorError> {
+        let bytes = self.edid()?;
+        Edid::parse(&bytes).map_err(|source| MonitorError::ParseEdid {
+            monitor: self.clone(),
+            source,
+        })
+    }
+
     fn params_key(&self) -> Result<RegKey, MonitorError> {
         fn helper(monitor: &Monitor) -> Result<RegKey, RegistryError> {
             let driver_key = Monitor::driver_key(&monitor.driver_id)?;
@@ -240,6 +264,12 @@ pub enum MonitorError {
         #[source]
         source: RegistryError,
     },
+    #[error("Error parsing EDID for monitor {monitor:?}")]
+    ParseEdid {
+        monitor: Monitor,
+        #[source]
+        source: EdidError,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -320,6 +350,17 @@ mod tests {
         println!("{:#?}", edids);
     }
 
+    #[test]
+    fn can_parse_edids() {
+        let monitors = Monitor::all().unwrap();
+        let edids = monitors
+            .iter()
+            .flat_map(Monitor::parse_edid)
+            .collect::<Vec<_>>();
+        assert!(edids.len() > 0);
+        println!("{:#?}", edids);
+    }
+
     #[test]
     fn can_list_monitors_for_hwnd() {
         use winit::{
@@ -1,4 +1,5 @@
 use crate::*;
+use display::{DisplayChangeError, DisplayMode};
 use physical_monitor::PhysicalMonitorError;
 use std::{mem, ptr};
 use thiserror::Error;
@@ -8,7 +9,8 @@ use winapi::{
         windef::{HDC, HMONITOR, RECT},
     },
     um::winuser::{
-        EnumDisplayMonitors, GetMonitorInfoW, MONITORINFO, MONITORINFOEXW, MONITORINFOF_PRIMARY,
+        EnumDisplayMonitors, GetMonitorInfoW, CDS_TEST, CDS_UPDATEREGISTRY,
+        ENUM_CURRENT_SETTINGS, MONITORINFO, MONITORINFOEXW, MONITORINFOF_PRIMARY,
     },
 };
 
@@ -25,6 +27,9 @@ pub struct Monitor {
     pub work_area: Rect,
     // If this is the primary display monitor.
     pub is_primary: bool,
+    // Cached null-terminated UTF-16 device name (`info.szDevice`), reused by
+    // every mode/position call instead of re-encoding `name` each time.
+    ffi_name: [u16; 32],
 }
 
 impl Monitor {
@@ -95,6 +100,77 @@ impl Monitor {
         PhysicalMonitor::list(self)
     }
 
+    /// Get the mode this monitor is currently using.
+    ///
+    /// ```
+    /// # use monitor_control_win::Monitor;
+    /// let monitor = Monitor::primary()?;
+    /// let mode = monitor.current_mode()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn current_mode(&self) -> Result<DisplayMode, MonitorError> {
+        self.mode_at(ENUM_CURRENT_SETTINGS)
+            .ok_or(MonitorError::GetCurrentMode)
+    }
+
+    /// List every mode this monitor's adapter supports.
+    ///
+    /// ```
+    /// # use monitor_control_win::Monitor;
+    /// let monitor = Monitor::primary()?;
+    /// let modes = monitor.list_modes();
+    /// println!("{:#?}", modes);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn list_modes(&self) -> Vec<DisplayMode> {
+        let mut modes = vec![];
+        let mut n = 0;
+        while let Some(mode) = self.mode_at(n) {
+            modes.push(mode);
+            n += 1;
+        }
+        modes
+    }
+
+    fn mode_at(&self, mode_num: u32) -> Option<DisplayMode> {
+        devmode::enum_mode(&self.ffi_name[0], mode_num)
+    }
+
+    /// Apply a display mode (resolution, refresh rate, color depth,
+    /// orientation) to this monitor.
+    ///
+    /// ```no_run
+    /// # use monitor_control_win::Monitor;
+    /// let monitor = Monitor::primary()?;
+    /// let mode = monitor.current_mode()?;
+    /// monitor.apply_mode(&mode)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn apply_mode(&self, mode: &DisplayMode) -> Result<(), MonitorError> {
+        self.change_mode(mode, CDS_UPDATEREGISTRY)
+    }
+
+    /// Check whether [`Self::apply_mode`] would accept `mode`, without
+    /// applying it, using the `CDS_TEST` flag.
+    pub fn test_mode(&self, mode: &DisplayMode) -> Result<(), MonitorError> {
+        self.change_mode(mode, CDS_TEST)
+    }
+
+    fn change_mode(&self, mode: &DisplayMode, flags: u32) -> Result<(), MonitorError> {
+        devmode::change_mode(
+            &self.ffi_name[0],
+            mode,
+            devmode::MODE_FIELDS_WITH_ORIENTATION,
+            flags,
+        )
+        .map_err(MonitorError::SetMode)
+    }
+
+    /// Move this monitor to `(x, y)` in virtual-screen coordinates.
+    pub fn set_position(&self, x: i32, y: i32) -> Result<(), MonitorError> {
+        devmode::change_position(&self.ffi_name[0], x, y).map_err(MonitorError::SetMode)
+    }
+
     fn get(h: HMONITOR) -> Result<Self, MonitorError> {
         let mut info = MONITORINFOEXW {
             cbSize: mem::size_of::<MONITORINFOEXW>() as u32,
@@ -120,6 +196,7 @@ impl Monitor {
             rect,
             work_area,
             is_primary,
+            ffi_name: info.szDevice,
         })
     }
 }
@@ -130,6 +207,10 @@ pub enum MonitorError {
     GotPlaceholder,
     #[error("No primary monitor")]
     NoPrimary,
+    #[error("Failed to get the monitor's current display mode")]
+    GetCurrentMode,
+    #[error("Failed to change the monitor's display mode")]
+    SetMode(#[source] DisplayChangeError),
 }
 
 #[cfg(test)]
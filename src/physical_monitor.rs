@@ -7,6 +7,8 @@ use winapi::{
     shared::{minwindef::HKEY, windef::HDC__, winerror},
     um::{
         handleapi::INVALID_HANDLE_VALUE,
+        highlevelmonitorconfigurationapi::{GetMonitorBrightness, SetMonitorBrightness},
+        lowlevelmonitorconfigurationapi::{GetVCPFeatureAndVCPFeatureReply, SetVCPFeature},
         physicalmonitorenumerationapi::{
             GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR,
             PHYSICAL_MONITOR,
@@ -53,10 +55,195 @@ impl PhysicalMonitor {
         let h = sys.hPhysicalMonitor as HMONITOR;
         Self { h, description }
     }
+
+    /// Get the current and maximum value of a VCP (Virtual Control Panel)
+    /// feature over DDC/CI.
+    ///
+    /// This is an escape hatch for MCCS feature codes without a typed
+    /// wrapper. See [`VcpCode`] for the common ones.
+    ///
+    /// ```no_run
+    /// # use monitor_control_win::Monitor;
+    /// let monitor = Monitor::primary()?.physical_monitors()?.remove(0);
+    /// let (current, max) = monitor.vcp_get(0x10)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn vcp_get(&self, code: u8) -> Result<(u16, u16), PhysicalMonitorError> {
+        let mut current = 0u32;
+        let mut max = 0u32;
+
+        let is_success = unsafe {
+            GetVCPFeatureAndVCPFeatureReply(
+                self.h as *mut c_void,
+                code,
+                ptr::null_mut(),
+                &mut current,
+                &mut max,
+            )
+        };
+        if !unffi_bool(is_success) {
+            return Err(PhysicalMonitorError::GetVcp {
+                code,
+                source: WinError::last(),
+            });
+        }
+
+        Ok((current as u16, max as u16))
+    }
+
+    /// Set a VCP (Virtual Control Panel) feature over DDC/CI.
+    ///
+    /// This is an escape hatch for MCCS feature codes without a typed
+    /// wrapper. See [`VcpCode`] for the common ones.
+    pub fn vcp_set(&self, code: u8, value: u16) -> Result<(), PhysicalMonitorError> {
+        let is_success = unsafe { SetVCPFeature(self.h as *mut c_void, code, value as u32) };
+        if !unffi_bool(is_success) {
+            return Err(PhysicalMonitorError::SetVcp {
+                code,
+                source: WinError::last(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get the current and maximum value of a typed MCCS VCP feature.
+    pub fn get_feature(&self, feature: VcpCode) -> Result<(u16, u16), PhysicalMonitorError> {
+        self.vcp_get(feature as u8)
+    }
+
+    /// Set a typed MCCS VCP feature.
+    pub fn set_feature(&self, feature: VcpCode, value: u16) -> Result<(), PhysicalMonitorError> {
+        self.vcp_set(feature as u8, value)
+    }
+
+    /// Get the monitor's minimum, current, and maximum brightness, via the
+    /// high-level `GetMonitorBrightness` API.
+    ///
+    /// This is a different mechanism than [`VcpCode::Brightness`]: it goes
+    /// through the monitor class driver rather than DDC/CI, so it only works
+    /// for displays that expose brightness that way (e.g. a laptop's
+    /// internal panel). For external monitors, use
+    /// `get_feature(VcpCode::Brightness)` instead.
+    pub fn brightness(&self) -> Result<(u16, u16, u16), PhysicalMonitorError> {
+        let mut min = 0u32;
+        let mut current = 0u32;
+        let mut max = 0u32;
+
+        let is_success = unsafe {
+            GetMonitorBrightness(self.h as *mut c_void, &mut min, &mut current, &mut max)
+        };
+        if !unffi_bool(is_success) {
+            return Err(PhysicalMonitorError::GetBrightness(WinError::last()));
+        }
+
+        Ok((min as u16, current as u16, max as u16))
+    }
+
+    /// Set the monitor's brightness, via the high-level `SetMonitorBrightness`
+    /// API. See [`Self::brightness`] for how this differs from the DDC/CI
+    /// `VcpCode::Brightness` feature.
+    pub fn set_brightness(&self, value: u32) -> Result<(), PhysicalMonitorError> {
+        let is_success = unsafe { SetMonitorBrightness(self.h as *mut c_void, value) };
+        if !unffi_bool(is_success) {
+            return Err(PhysicalMonitorError::SetBrightness(WinError::last()));
+        }
+
+        Ok(())
+    }
+
+    /// Get the monitor's DDC/CI contrast (`VcpCode::Contrast`).
+    pub fn contrast(&self) -> Result<(u16, u16), PhysicalMonitorError> {
+        self.get_feature(VcpCode::Contrast)
+    }
+
+    /// Set the monitor's DDC/CI contrast (`VcpCode::Contrast`).
+    pub fn set_contrast(&self, value: u16) -> Result<(), PhysicalMonitorError> {
+        self.set_feature(VcpCode::Contrast, value)
+    }
+
+    /// Get the monitor's DDC/CI input source (`VcpCode::InputSource`).
+    pub fn input_source(&self) -> Result<(u16, u16), PhysicalMonitorError> {
+        self.get_feature(VcpCode::InputSource)
+    }
+
+    /// Set the monitor's DDC/CI input source (`VcpCode::InputSource`).
+    pub fn set_input_source(&self, value: u16) -> Result<(), PhysicalMonitorError> {
+        self.set_feature(VcpCode::InputSource, value)
+    }
+
+    /// Get the monitor's DDC/CI power mode / DPMS state (`VcpCode::PowerMode`).
+    pub fn power_mode(&self) -> Result<(u16, u16), PhysicalMonitorError> {
+        self.get_feature(VcpCode::PowerMode)
+    }
+
+    /// Set the monitor's DDC/CI power mode / DPMS state (`VcpCode::PowerMode`).
+    pub fn set_power_mode(&self, value: u16) -> Result<(), PhysicalMonitorError> {
+        self.set_feature(VcpCode::PowerMode, value)
+    }
+}
+
+/// Common MCCS (Monitor Control Command Set) VCP feature codes.
+///
+/// This is not exhaustive; use [`PhysicalMonitor::vcp_get`] /
+/// [`PhysicalMonitor::vcp_set`] directly for codes not listed here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcpCode {
+    /// VCP 0x10: luminance/brightness.
+    Brightness = 0x10,
+    /// VCP 0x12: contrast.
+    Contrast = 0x12,
+    /// VCP 0x60: input source selection.
+    InputSource = 0x60,
+    /// VCP 0xD6: power mode / DPMS state.
+    PowerMode = 0xD6,
 }
 
 #[derive(Debug, Error)]
 pub enum PhysicalMonitorError {
     #[error("Error listing physical monitors associated with monitor")]
     Listing(#[source] WinError),
+    #[error("Error getting VCP feature 0x{code:X}")]
+    GetVcp {
+        code: u8,
+        #[source]
+        source: WinError,
+    },
+    #[error("Error setting VCP feature 0x{code:X}")]
+    SetVcp {
+        code: u8,
+        #[source]
+        source: WinError,
+    },
+    #[error("Error getting monitor brightness")]
+    GetBrightness(#[source] WinError),
+    #[error("Error setting monitor brightness")]
+    SetBrightness(#[source] WinError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn physical() -> PhysicalMonitor {
+        Monitor::primary()
+            .unwrap()
+            .physical_monitors()
+            .unwrap()
+            .remove(0)
+    }
+
+    #[test]
+    fn can_get_vcp() {
+        let monitor = physical();
+        let brightness = monitor.vcp_get(VcpCode::Brightness as u8).unwrap();
+        panic!("{:#?}", brightness);
+    }
+
+    #[test]
+    fn can_get_brightness() {
+        let monitor = physical();
+        let brightness = monitor.brightness().unwrap();
+        panic!("{:#?}", brightness);
+    }
 }
@@ -0,0 +1,232 @@
+//! Display hotplug/reconfiguration notifications.
+//!
+//! Both [`crate::Monitor::list`] (GDI) and [`crate::Monitor::all`]
+//! (registry) are one-shot snapshots; [`Watcher`] lets callers react when
+//! monitors are plugged, unplugged, or reconfigured instead of polling.
+
+use crate::WinError;
+use std::{
+    mem, ptr,
+    sync::mpsc::{channel, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+use thiserror::Error;
+use winapi::{
+    shared::{
+        minwindef::{LPARAM, LRESULT, UINT, WPARAM},
+        windef::HWND,
+    },
+    um::{
+        libloaderapi::GetModuleHandleW,
+        winuser::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW,
+            GetWindowLongPtrW, GetMessageW, PostMessageW, PostQuitMessage, RegisterClassW,
+            SetWindowLongPtrW, TranslateMessage, GWLP_USERDATA, HWND_MESSAGE, MSG, WM_CLOSE,
+            WM_DESTROY, WM_DEVICECHANGE, WM_DISPLAYCHANGE, WNDCLASSW,
+        },
+    },
+};
+
+/// An event delivered by [`Watcher`] when the display topology changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayEvent {
+    /// `WM_DISPLAYCHANGE`: the display resolution, color depth, or monitor
+    /// topology changed.
+    DisplayChanged,
+    /// `WM_DEVICECHANGE`: a device (not necessarily a display) was
+    /// added, removed, or reconfigured. Some hotplug events only show up
+    /// here, not as `WM_DISPLAYCHANGE`.
+    DeviceChanged,
+}
+
+/// Watches for display hotplug/reconfiguration events.
+///
+/// Runs a hidden message-only window on its own thread, forwarding
+/// `WM_DISPLAYCHANGE`/`WM_DEVICECHANGE` as [`DisplayEvent`]s over a channel.
+/// When an event arrives, re-run [`crate::Monitor::list`]/
+/// [`crate::Monitor::all`] to pick up the new topology. The window and
+/// thread are torn down when this is dropped.
+///
+/// ```no_run
+/// # use monitor_control_win::watcher::Watcher;
+/// let watcher = Watcher::start()?;
+/// for event in watcher.events() {
+///     println!("{:?}", event);
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct Watcher {
+    // The message-only window's handle, as a thread-independent integer;
+    // HWND itself is not `Send`.
+    hwnd: isize,
+    thread: Option<JoinHandle<()>>,
+    events: Receiver<DisplayEvent>,
+}
+
+const CLASS_NAME: &str = "monitor_control_win::Watcher";
+
+impl Watcher {
+    /// Start watching for display-change events on a background thread.
+    pub fn start() -> Result<Self, WatcherError> {
+        let (ready_tx, ready_rx) = channel::<Result<isize, WatcherError>>();
+        let (event_tx, event_rx) = channel();
+
+        let thread = thread::spawn(move || Self::run(ready_tx, event_tx));
+
+        let hwnd = ready_rx.recv().map_err(|_| WatcherError::ThreadDied)??;
+
+        Ok(Self {
+            hwnd,
+            thread: Some(thread),
+            events: event_rx,
+        })
+    }
+
+    /// The receiving end of the event channel. Stops yielding once this
+    /// [`Watcher`] is dropped.
+    pub fn events(&self) -> &Receiver<DisplayEvent> {
+        &self.events
+    }
+
+    fn run(ready: Sender<Result<isize, WatcherError>>, events: Sender<DisplayEvent>) {
+        let hwnd = match Self::create_window(events) {
+            Ok(hwnd) => hwnd,
+            Err(e) => {
+                let _ = ready.send(Err(e));
+                return;
+            }
+        };
+
+        if ready.send(Ok(hwnd as isize)).is_err() {
+            unsafe {
+                DestroyWindow(hwnd);
+            }
+            return;
+        }
+
+        let mut msg = MSG::default();
+        loop {
+            let status = unsafe { GetMessageW(&mut msg, ptr::null_mut(), 0, 0) };
+            if status <= 0 {
+                break;
+            }
+            unsafe {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+    }
+
+    fn create_window(events: Sender<DisplayEvent>) -> Result<HWND, WatcherError> {
+        let class_name: Vec<u16> = CLASS_NAME.encode_utf16().chain(Some(0)).collect();
+        let instance = unsafe { GetModuleHandleW(ptr::null()) };
+
+        let class = WNDCLASSW {
+            lpfnWndProc: Some(window_proc),
+            hInstance: instance,
+            lpszClassName: class_name.as_ptr(),
+            ..unsafe { mem::zeroed() }
+        };
+        // Registering the class twice (e.g. a second `Watcher`) fails
+        // harmlessly; if the class is genuinely unusable `CreateWindowExW`
+        // below will fail instead.
+        unsafe {
+            RegisterClassW(&class);
+        }
+
+        let hwnd = unsafe {
+            CreateWindowExW(
+                0,
+                class_name.as_ptr(),
+                ptr::null(),
+                0,
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                ptr::null_mut(),
+                instance,
+                ptr::null_mut(),
+            )
+        };
+        if hwnd.is_null() {
+            return Err(WatcherError::CreateWindow(WinError::last()));
+        }
+
+        let sender = Box::into_raw(Box::new(events));
+        unsafe {
+            SetWindowLongPtrW(hwnd, GWLP_USERDATA, sender as isize);
+        }
+
+        Ok(hwnd)
+    }
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        unsafe {
+            PostMessageW(self.hwnd as HWND, WM_CLOSE, 0, 0);
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    msg: UINT,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_DISPLAYCHANGE => {
+            send_event(hwnd, DisplayEvent::DisplayChanged);
+            0
+        }
+        WM_DEVICECHANGE => {
+            send_event(hwnd, DisplayEvent::DeviceChanged);
+            0
+        }
+        WM_CLOSE => {
+            let sender = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *mut Sender<DisplayEvent>;
+            if !sender.is_null() {
+                drop(Box::from_raw(sender));
+            }
+            DestroyWindow(hwnd);
+            0
+        }
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            0
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+unsafe fn send_event(hwnd: HWND, event: DisplayEvent) {
+    let sender = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *const Sender<DisplayEvent>;
+    if let Some(sender) = sender.as_ref() {
+        let _ = sender.send(event);
+    }
+}
+
+#[derive(Debug, Error, Clone, Copy)]
+pub enum WatcherError {
+    #[error("Failed to create the watcher's message-only window")]
+    CreateWindow(#[source] WinError),
+    #[error("The watcher's background thread exited unexpectedly")]
+    ThreadDied,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_start_and_stop() {
+        let watcher = Watcher::start().unwrap();
+        drop(watcher);
+    }
+}